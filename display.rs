@@ -2,6 +2,7 @@ use core::result;
 
 use embedded_hal::blocking::i2c::{Write, WriteRead};
 use embedded_hal_1::delay::DelayNs;
+use heapless::Vec;
 use mcp23017::{Error, PinMode, MCP23017};
 
 #[repr(u8)]
@@ -68,6 +69,7 @@ pub const LCD_SETDDRAMADDR: u8 = 0x80;
 // Entry flags
 pub const LCD_ENTRYLEFT: u8 = 0x02;
 pub const LCD_ENTRYSHIFTDECREMENT: u8 = 0x00;
+pub const LCD_ENTRYSHIFTINCREMENT: u8 = 0x01;
 
 // Control flags
 pub const LCD_DISPLAYON: u8 = 0x04;
@@ -86,6 +88,18 @@ pub const LCD_4BITMODE: u8 = 0x00;
 pub const LCD_2LINE: u8 = 0x08;
 pub const LCD_1LINE: u8 = 0x00;
 pub const LCD_5X8DOTS: u8 = 0x00;
+pub const LCD_5X10DOTS: u8 = 0x04;
+
+/// Character glyph size, set at construction time via `FontSize`.
+///
+/// `Dots5x10` only works in single-line mode; the HD44780 has no 5x10
+/// two-line font, so `new()`/`initialize()` force single-line when it's
+/// selected regardless of the requested `lines` count.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum FontSize {
+    Dots5x8,
+    Dots5x10,
+}
 
 // Direction constants
 pub const LEFT_TO_RIGHT: usize = 0;
@@ -114,7 +128,89 @@ impl From<embassy_rp::i2c::Error> for LcdError {
     }
 }
 
-pub struct CharLCDRGBI2C<I2C: Write + WriteRead, D: DelayNs> {
+const SETTINGS_MAGIC: u8 = 0xA5;
+const SETTINGS_LEN: usize = 7;
+
+/// Persisted LCD state: last-used backlight color, backlight on/off, and
+/// the display control/entry-mode flags. Restored on boot by `new()` and
+/// updated by `set_color`/`set_backlight` so the panel survives a power
+/// cycle looking the way it was left.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct LcdSettings {
+    pub color_value: [u8; 3],
+    pub backlight: bool,
+    pub display_control: u8,
+    pub display_mode: u8,
+}
+
+impl Default for LcdSettings {
+    fn default() -> Self {
+        LcdSettings {
+            color_value: [0, 0, 0],
+            backlight: true,
+            display_control: LCD_DISPLAYON | LCD_CURSOROFF | LCD_BLINKOFF,
+            display_mode: LCD_ENTRYLEFT | LCD_ENTRYSHIFTDECREMENT,
+        }
+    }
+}
+
+impl LcdSettings {
+    /// Encodes settings to the fixed byte layout `Storage` backends read
+    /// and write: magic, then color, backlight, control, mode.
+    pub fn to_bytes(&self) -> [u8; SETTINGS_LEN] {
+        [
+            SETTINGS_MAGIC,
+            self.color_value[0],
+            self.color_value[1],
+            self.color_value[2],
+            self.backlight as u8,
+            self.display_control,
+            self.display_mode,
+        ]
+    }
+
+    /// Decodes a raw settings buffer, falling back to `Default` when the
+    /// magic header doesn't match (e.g. blank/erased flash or EEPROM).
+    pub fn from_bytes(bytes: &[u8; SETTINGS_LEN]) -> Self {
+        if bytes[0] != SETTINGS_MAGIC {
+            return Self::default();
+        }
+
+        LcdSettings {
+            color_value: [bytes[1], bytes[2], bytes[3]],
+            backlight: bytes[4] != 0,
+            display_control: bytes[5],
+            display_mode: bytes[6],
+        }
+    }
+}
+
+/// Backend for persisting `LcdSettings` across power cycles — a flash
+/// page, an external I2C EEPROM, etc. Kept generic so the same
+/// `CharLCDRGBI2C` works unchanged on both the STM32 and RP2040 targets
+/// this crate targets.
+pub trait Storage {
+    fn load(&mut self) -> Result<LcdSettings, LcdError>;
+    fn store(&mut self, settings: &LcdSettings) -> Result<(), LcdError>;
+}
+
+/// No-op `Storage` backend for callers who don't want persistence:
+/// `load` reports defaults and `store` discards the settings. Pass
+/// `Some(NoStorage)`/`None::<NoStorage>` to `new()` to opt out of a real
+/// backend while still giving `S` a concrete type to infer.
+pub struct NoStorage;
+
+impl Storage for NoStorage {
+    fn load(&mut self) -> Result<LcdSettings, LcdError> {
+        Ok(LcdSettings::default())
+    }
+
+    fn store(&mut self, _settings: &LcdSettings) -> Result<(), LcdError> {
+        Ok(())
+    }
+}
+
+pub struct CharLCDRGBI2C<I2C: Write + WriteRead, D: DelayNs, S: Storage> {
     mcp: MCP23017<I2C>,
     delay: D,
     columns: usize,
@@ -125,28 +221,103 @@ pub struct CharLCDRGBI2C<I2C: Write + WriteRead, D: DelayNs> {
     display_control: u8,
     display_mode: u8,
     display_function: u8,
+    font_size: FontSize,
+    use_busy_flag: bool,
+    buttons: [ButtonState; 5],
+    storage: Option<S>,
+    // Mirrors whatever was last written to `storage` (or loaded from it),
+    // so persist_settings() can skip redundant writes/flash wear when
+    // nothing has actually changed.
+    persisted_settings: LcdSettings,
     row: usize,
     column: usize,
     column_align: bool,
     direction: usize,
 }
 
-impl<E, I2C: Write<Error=E> + WriteRead<Error = E>, D: DelayNs> CharLCDRGBI2C<I2C, D> where LcdError: From<E> {
-    pub fn new(i2c: I2C, delay: D, columns: usize, lines: usize) -> Result<Self, LcdError> {
+// Bounded iteration count for wait_busy() so a disconnected/miswired panel
+// can't hang the caller forever waiting for a busy flag that never clears.
+const MAX_BUSY_POLLS: u32 = 1000;
+
+// poll_events() pacing and debounce tuning, expressed in ticks of one
+// poll_events() call each (see BUTTON_TICK_MS).
+const BUTTON_TICK_MS: u32 = 10;
+const DEBOUNCE_TICKS: u8 = 3;
+const REPEAT_START_TICKS: u32 = 50; // ~500ms held before auto-repeat kicks in
+const REPEAT_INTERVAL_TICKS: u32 = 10; // ~100ms between repeats thereafter
+
+// Software-PWM frame used by refresh_color(): the frame is divided into
+// this many steps, each held for PWM_STEP_US, so a channel's duty cycle is
+// resolved to one of PWM_STEPS + 1 brightness levels (0 = off through
+// PWM_STEPS = on for the whole frame).
+const PWM_STEPS: u32 = 32;
+const PWM_STEP_US: u32 = 60;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Button {
+    Left,
+    Right,
+    Up,
+    Down,
+    Select,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ButtonEvent {
+    Pressed(Button),
+    Released(Button),
+    Repeat(Button, u32),
+}
+
+#[derive(Copy, Clone, Default)]
+struct ButtonState {
+    raw_pressed: bool,
+    debounced_pressed: bool,
+    stable_ticks: u8,
+    held_ticks: u32,
+    repeats: u32,
+}
+
+impl<E, I2C: Write<Error=E> + WriteRead<Error = E>, D: DelayNs, S: Storage> CharLCDRGBI2C<I2C, D, S> where LcdError: From<E> {
+    /// Set `use_busy_flag` to `false` for panels whose RW line is tied to
+    /// ground instead of wired to the MCP23017, falling back to fixed
+    /// delays for command timing. Pass `None::<NoStorage>` (or any other
+    /// `Storage` wrapped in `None`) to skip persistence entirely;
+    /// otherwise last-used color/backlight/control settings are loaded
+    /// from it here and restored before the LCD is initialized.
+    pub fn new(
+        i2c: I2C,
+        delay: D,
+        columns: usize,
+        lines: usize,
+        font_size: FontSize,
+        use_busy_flag: bool,
+        mut storage: Option<S>,
+    ) -> Result<Self, LcdError> {
         // Use map_err for the MCP error conversion
         let mcp = MCP23017::default(i2c)?;
 
+        let settings = match storage.as_mut() {
+            Some(storage) => storage.load().unwrap_or_default(),
+            None => LcdSettings::default(),
+        };
+
         let mut lcd = CharLCDRGBI2C {
             mcp,
             delay,
             columns,
             lines,
-            backlight: true,
+            backlight: settings.backlight,
             rgb: [RGB_RED, RGB_GREEN, RGB_BLUE],
-            color_value: [0, 0, 0],
-            display_control: 0,
-            display_mode: 0,
+            color_value: settings.color_value,
+            display_control: settings.display_control,
+            display_mode: settings.display_mode,
             display_function: 0,
+            font_size,
+            use_busy_flag,
+            buttons: [ButtonState::default(); 5],
+            storage,
+            persisted_settings: settings,
             row: 0,
             column: 0,
             column_align: false,
@@ -198,10 +369,21 @@ impl<E, I2C: Write<Error=E> + WriteRead<Error = E>, D: DelayNs> CharLCDRGBI2C<I2
         self.write4bits(0x02)?; // Set to 4-bit mode
         self.delay.delay_ms(1);
 
-        // Initialize display control
-        self.display_control = LCD_DISPLAYON | LCD_CURSOROFF | LCD_BLINKOFF;
-        self.display_function = LCD_4BITMODE | LCD_1LINE | LCD_2LINE | LCD_5X8DOTS;
-        self.display_mode = LCD_ENTRYLEFT | LCD_ENTRYSHIFTDECREMENT;
+        // display_control/display_mode already hold either the persisted
+        // settings or LcdSettings::default(), set in new(); only
+        // display_function is (re)computed here since font/lines aren't
+        // persisted.
+        let font_bits = match self.font_size {
+            FontSize::Dots5x8 => LCD_5X8DOTS,
+            FontSize::Dots5x10 => LCD_5X10DOTS,
+        };
+        // The 5x10 font only exists in single-line mode.
+        let line_bits = if self.font_size == FontSize::Dots5x10 || self.lines <= 1 {
+            LCD_1LINE
+        } else {
+            LCD_2LINE
+        };
+        self.display_function = LCD_4BITMODE | line_bits | font_bits;
 
         // Write to display control
         self.write_command(LCD_DISPLAYCONTROL | self.display_control)?;
@@ -219,8 +401,10 @@ impl<E, I2C: Write<Error=E> + WriteRead<Error = E>, D: DelayNs> CharLCDRGBI2C<I2
         self.column_align = false;
         self.direction = LEFT_TO_RIGHT;
 
-        // Turn off all RGB LEDs initially
-        self.set_color(0, 0, 0)?;
+        // Restore the persisted (or default) color and backlight state.
+        let [r, g, b] = self.color_value;
+        self.set_color(r, g, b)?;
+        self.set_backlight(self.backlight)?;
 
         Ok(())
     }
@@ -269,6 +453,9 @@ impl<E, I2C: Write<Error=E> + WriteRead<Error = E>, D: DelayNs> CharLCDRGBI2C<I2
         self.write4bits(value >> 4)?;
         // Send lower 4 bits
         self.write4bits(value & 0x0F)?;
+
+        self.wait_busy()?;
+
         Ok(())
     }
 
@@ -279,7 +466,7 @@ impl<E, I2C: Write<Error=E> + WriteRead<Error = E>, D: DelayNs> CharLCDRGBI2C<I2
 
     pub fn clear(&mut self) -> Result<(), LcdError> {
         self.write_command(LCD_CLEARDISPLAY)?;
-        self.delay.delay_ms(3);
+        self.wait_long_command()?;
         self.row = 0;
         self.column = 0;
         Ok(())
@@ -287,12 +474,75 @@ impl<E, I2C: Write<Error=E> + WriteRead<Error = E>, D: DelayNs> CharLCDRGBI2C<I2
 
     pub fn home(&mut self) -> Result<(), LcdError> {
         self.write_command(LCD_RETURNHOME)?;
-        self.delay.delay_ms(3);
+        self.wait_long_command()?;
         self.row = 0;
         self.column = 0;
         Ok(())
     }
 
+    /// `wait_busy()` (run inside `write_command`) already blocks for
+    /// ordinary commands, but on the delay-fallback path it only waits
+    /// `delay_us(100)` — far short of the ~1.52ms `LCD_CLEARDISPLAY`/
+    /// `LCD_RETURNHOME` need to finish. Pad those two commands with an
+    /// extra fixed delay when there's no busy flag to poll.
+    fn wait_long_command(&mut self) -> Result<(), LcdError> {
+        if !self.use_busy_flag {
+            self.delay.delay_ms(3);
+        }
+        Ok(())
+    }
+
+    /// Reads one 4-bit nibble back over `D4..D7` with `E` pulsed, assuming
+    /// the data pins are already switched to inputs and `RW` is high.
+    fn read_nibble(&mut self) -> Result<u8, LcdError> {
+        self.mcp.digital_write(LCD_E as u8, true)?;
+        self.delay.delay_us(1);
+
+        let d4 = self.mcp.digital_read(LCD_D4 as u8)?;
+        let d5 = self.mcp.digital_read(LCD_D5 as u8)?;
+        let d6 = self.mcp.digital_read(LCD_D6 as u8)?;
+        let d7 = self.mcp.digital_read(LCD_D7 as u8)?;
+
+        self.mcp.digital_write(LCD_E as u8, false)?;
+        self.delay.delay_us(1);
+
+        Ok(d4 as u8 | (d5 as u8) << 1 | (d6 as u8) << 2 | (d7 as u8) << 3)
+    }
+
+    /// Blocks until the HD44780 clears its busy flag, or falls back to a
+    /// fixed delay on panels constructed with `use_busy_flag = false`
+    /// (e.g. ones with RW grounded).
+    fn wait_busy(&mut self) -> Result<(), LcdError> {
+        if !self.use_busy_flag {
+            self.delay.delay_us(100);
+            return Ok(());
+        }
+
+        for pin in [LCD_D4, LCD_D5, LCD_D6, LCD_D7] {
+            self.mcp.pin_mode(pin as u8, PinMode::INPUT)?;
+        }
+        self.mcp.digital_write(LCD_RS as u8, false)?;
+        self.mcp.digital_write(LCD_RW as u8, true)?;
+
+        for _ in 0..MAX_BUSY_POLLS {
+            let upper = self.read_nibble()?;
+            // Second nibble carries the address counter; nothing here needs
+            // it, but it must still be clocked through to finish the cycle.
+            let _address_counter = self.read_nibble()?;
+
+            if upper & 0x08 == 0 {
+                break;
+            }
+        }
+
+        self.mcp.digital_write(LCD_RW as u8, false)?;
+        for pin in [LCD_D4, LCD_D5, LCD_D6, LCD_D7] {
+            self.mcp.pin_mode(pin as u8, PinMode::OUTPUT)?;
+        }
+
+        Ok(())
+    }
+
     pub fn set_color(&mut self, r: u8, g: u8, b: u8) -> Result<(), LcdError> {
         // Any value > 1 turns LED on (inverse of Python logic)
         // LOW = on for common anode RGB LED
@@ -304,6 +554,54 @@ impl<E, I2C: Write<Error=E> + WriteRead<Error = E>, D: DelayNs> CharLCDRGBI2C<I2
             .digital_write(self.rgb[2] as u8, if b > 1 { false } else { true })?; // B
 
         self.color_value = [r, g, b];
+        self.persist_settings()
+    }
+
+    /// Writes the current color/backlight/control settings to `storage`,
+    /// if one was supplied to `new()` and the settings actually changed
+    /// since the last write (or the initial load) — so redundant setter
+    /// calls, and restoring already-persisted state in `initialize()`,
+    /// don't wear out a flash/EEPROM backend.
+    fn persist_settings(&mut self) -> Result<(), LcdError> {
+        let current = LcdSettings {
+            color_value: self.color_value,
+            backlight: self.backlight,
+            display_control: self.display_control,
+            display_mode: self.display_mode,
+        };
+
+        if current == self.persisted_settings {
+            return Ok(());
+        }
+
+        if let Some(storage) = self.storage.as_mut() {
+            storage.store(&current)?;
+        }
+
+        self.persisted_settings = current;
+        Ok(())
+    }
+
+    /// Runs one software-PWM frame across the RGB pins, driving each
+    /// common-anode channel low for a fraction of the frame proportional to
+    /// its 8-bit `color_value`, scaled by `brightness` (0..255). This is
+    /// what makes mixed colors (e.g. orange, purple) possible, since the
+    /// MCP23017 has no hardware PWM — call it periodically (e.g. once per
+    /// main-loop iteration) for steady output; a single call only produces
+    /// one frame's worth of light.
+    pub fn refresh_color(&mut self, brightness: u8) -> Result<(), LcdError> {
+        let duty: [u32; 3] = core::array::from_fn(|i| {
+            self.color_value[i] as u32 * brightness as u32 / 255 * PWM_STEPS / 255
+        });
+
+        for step in 0..PWM_STEPS {
+            for (pin, duty) in self.rgb.into_iter().zip(duty) {
+                // LOW = on for a common-anode RGB LED
+                self.mcp.digital_write(pin as u8, step >= duty)?;
+            }
+            self.delay.delay_us(PWM_STEP_US);
+        }
+
         Ok(())
     }
 
@@ -332,7 +630,7 @@ impl<E, I2C: Write<Error=E> + WriteRead<Error = E>, D: DelayNs> CharLCDRGBI2C<I2
             self.backlight = false;
             // println!("Backlight OFF")
         }
-        Ok(())
+        self.persist_settings()
     }
 
     pub fn cursor_position(&mut self, mut column: usize, mut row: usize) -> Result<(), LcdError> {
@@ -348,9 +646,95 @@ impl<E, I2C: Write<Error=E> + WriteRead<Error = E>, D: DelayNs> CharLCDRGBI2C<I2
         Ok(())
     }
 
+    /// Turns the display itself on or off without touching cursor/blink state.
+    pub fn display(&mut self, on: bool) -> Result<(), LcdError> {
+        if on {
+            self.display_control |= LCD_DISPLAYON;
+        } else {
+            self.display_control &= !LCD_DISPLAYON;
+        }
+        self.write_command(LCD_DISPLAYCONTROL | self.display_control)?;
+        self.persist_settings()
+    }
+
+    /// Shows or hides the underline cursor.
+    pub fn show_cursor(&mut self, on: bool) -> Result<(), LcdError> {
+        if on {
+            self.display_control |= LCD_CURSORON;
+        } else {
+            self.display_control &= !LCD_CURSORON;
+        }
+        self.write_command(LCD_DISPLAYCONTROL | self.display_control)?;
+        self.persist_settings()
+    }
+
+    /// Turns the blinking-block cursor on or off.
+    pub fn blink(&mut self, on: bool) -> Result<(), LcdError> {
+        if on {
+            self.display_control |= LCD_BLINKON;
+        } else {
+            self.display_control &= !LCD_BLINKON;
+        }
+        self.write_command(LCD_DISPLAYCONTROL | self.display_control)?;
+        self.persist_settings()
+    }
+
+    /// Sets text entry direction to `LEFT_TO_RIGHT` or `RIGHT_TO_LEFT`.
+    pub fn text_direction(&mut self, direction: usize) -> Result<(), LcdError> {
+        if direction == LEFT_TO_RIGHT {
+            self.display_mode |= LCD_ENTRYLEFT;
+        } else {
+            self.display_mode &= !LCD_ENTRYLEFT;
+        }
+        self.direction = direction;
+        self.write_command(LCD_ENTRYMODESET | self.display_mode)?;
+        self.persist_settings()
+    }
+
+    /// Enables/disables autoscroll, which shifts the whole display instead
+    /// of the cursor as characters are written.
+    pub fn autoscroll(&mut self, on: bool) -> Result<(), LcdError> {
+        if on {
+            self.display_mode |= LCD_ENTRYSHIFTINCREMENT;
+        } else {
+            self.display_mode &= !LCD_ENTRYSHIFTINCREMENT;
+        }
+        self.write_command(LCD_ENTRYMODESET | self.display_mode)?;
+        self.persist_settings()
+    }
+
+    /// Shifts the whole display one position left without changing DDRAM.
+    pub fn scroll_display_left(&mut self) -> Result<(), LcdError> {
+        self.write_command(LCD_CURSORSHIFT | LCD_DISPLAYMOVE | LCD_MOVELEFT)
+    }
+
+    /// Shifts the whole display one position right without changing DDRAM.
+    pub fn scroll_display_right(&mut self) -> Result<(), LcdError> {
+        self.write_command(LCD_CURSORSHIFT | LCD_DISPLAYMOVE | LCD_MOVERIGHT)
+    }
+
+    /// Writes `text` to `row` and, if it's longer than `columns`, scrolls it
+    /// step-by-step with `delay_ms` between steps so the whole line becomes
+    /// readable instead of being truncated.
+    pub fn marquee(&mut self, text: &str, row: usize, delay_ms: u32) -> Result<(), LcdError> {
+        self.cursor_position(0, row)?;
+        self.message(text)?;
+
+        let len = text.chars().count();
+        if len <= self.columns {
+            return Ok(());
+        }
+
+        for _ in 0..(len - self.columns) {
+            self.delay.delay_ms(delay_ms);
+            self.scroll_display_left()?;
+        }
+
+        Ok(())
+    }
+
     pub fn message(&mut self, message: &str) -> Result<(), LcdError> {
 
-        let mut line = self.row;
         let mut initial_character = 0;
 
         for char in message.chars() {
@@ -361,12 +745,12 @@ impl<E, I2C: Write<Error=E> + WriteRead<Error = E>, D: DelayNs> CharLCDRGBI2C<I2
                 } else {
                     col = self.columns - 1 - self.column;
                 }
-                self.cursor_position(col, line)?;
+                self.cursor_position(col, self.row)?;
                 initial_character += 1;
             }
 
             if char == '\n' {
-                line += 1;
+                let line = self.row + 1;
                 let col;
                 if self.display_mode & LCD_ENTRYLEFT > 0 {
                     if self.column_align {
@@ -384,11 +768,50 @@ impl<E, I2C: Write<Error=E> + WriteRead<Error = E>, D: DelayNs> CharLCDRGBI2C<I2
                 self.cursor_position(col, line)?;
             } else {
                 self.write8(char as u8, true)?;
+                // The HD44780 auto-increments its DDRAM address as each
+                // character is written; mirror that in software so a
+                // following message()/write_str() call (e.g. from
+                // core::fmt::Write) resumes where this one left off
+                // instead of re-homing to (0, 0).
+                if self.display_mode & LCD_ENTRYLEFT > 0 {
+                    // Clamp rather than let this grow past the last
+                    // column: an overlong line (no '\n') would otherwise
+                    // leave self.column >= self.columns, and the next
+                    // RIGHT_TO_LEFT message() underflows computing
+                    // `self.columns - 1 - self.column`.
+                    self.column = (self.column + 1).min(self.columns.saturating_sub(1));
+                } else if self.column > 0 {
+                    self.column -= 1;
+                }
             }
         }
 
-        self.column = 0;
-        self.row = 0;
+        // cursor_position() leaves self.column holding the hardware column,
+        // but in RIGHT_TO_LEFT mode the next message() maps it back through
+        // `self.columns - 1 - self.column`; store it in that form so the next
+        // call lands on the same hardware column.
+        if initial_character > 0 && self.display_mode & LCD_ENTRYLEFT == 0 {
+            self.column = self.columns - 1 - self.column;
+        }
+
+        Ok(())
+    }
+
+    /// Loads a custom 5x8 glyph into CGRAM so it can be printed through
+    /// `message()` as char code `location` (0..7).
+    pub fn create_char(&mut self, location: usize, pattern: &[u8; 8]) -> Result<(), LcdError> {
+        let location = location & 0x7;
+        self.write_command(LCD_SETCGRAMADDR | ((location as u8) << 3))?;
+
+        for row in pattern {
+            self.write8(*row, true)?;
+        }
+
+        // Writing to CGRAM leaves the address counter pointed at CGRAM;
+        // restore the DDRAM cursor so subsequent message()/write8() calls
+        // resume where the caller left off.
+        let (row, column) = (self.row, self.column);
+        self.set_cursor(column, row)?;
 
         Ok(())
     }
@@ -412,4 +835,87 @@ impl<E, I2C: Write<Error=E> + WriteRead<Error = E>, D: DelayNs> CharLCDRGBI2C<I2
     pub fn read_button_select(&mut self) -> Result<bool, LcdError> {
         Ok(self.mcp.digital_read(BTN_SELECT as u8)?)
     }
+
+    /// Reads all five buttons, debounces them, and reports the resulting
+    /// `Pressed`/`Released`/`Repeat` transitions. Call this once per loop
+    /// iteration instead of the raw `read_button_*` getters; it paces
+    /// itself with `delay_ms(BUTTON_TICK_MS)` so callers can just loop.
+    pub fn poll_events(&mut self) -> Result<Vec<ButtonEvent, 8>, LcdError> {
+        let mut events = Vec::new();
+
+        let readings = [
+            (Button::Left, self.mcp.digital_read(BTN_LEFT as u8)?),
+            (Button::Right, self.mcp.digital_read(BTN_RIGHT as u8)?),
+            (Button::Up, self.mcp.digital_read(BTN_UP as u8)?),
+            (Button::Down, self.mcp.digital_read(BTN_DOWN as u8)?),
+            (Button::Select, self.mcp.digital_read(BTN_SELECT as u8)?),
+        ];
+
+        for (index, (button, level)) in readings.into_iter().enumerate() {
+            let pressed = !level; // buttons are active-low with pull-ups
+            let state = &mut self.buttons[index];
+
+            if pressed == state.raw_pressed {
+                if state.stable_ticks < DEBOUNCE_TICKS {
+                    state.stable_ticks += 1;
+                }
+            } else {
+                state.raw_pressed = pressed;
+                state.stable_ticks = 0;
+            }
+
+            if state.stable_ticks == DEBOUNCE_TICKS && pressed != state.debounced_pressed {
+                state.debounced_pressed = pressed;
+                state.held_ticks = 0;
+                state.repeats = 0;
+
+                let _ = events.push(if pressed {
+                    ButtonEvent::Pressed(button)
+                } else {
+                    ButtonEvent::Released(button)
+                });
+            } else if state.debounced_pressed && pressed {
+                state.held_ticks += 1;
+
+                if state.held_ticks >= REPEAT_START_TICKS
+                    && (state.held_ticks - REPEAT_START_TICKS) % REPEAT_INTERVAL_TICKS == 0
+                {
+                    state.repeats += 1;
+                    let _ = events.push(ButtonEvent::Repeat(button, state.repeats));
+                }
+            }
+        }
+
+        self.delay.delay_ms(BUTTON_TICK_MS);
+
+        Ok(events)
+    }
+}
+
+impl<E, I2C: Write<Error = E> + WriteRead<Error = E>, D: DelayNs> CharLCDRGBI2C<I2C, D, NoStorage>
+where
+    LcdError: From<E>,
+{
+    /// Shorthand for `new()` with no persistence backend, so the common
+    /// case doesn't need a `None::<NoStorage>` turbofish at the call site.
+    pub fn new_without_storage(
+        i2c: I2C,
+        delay: D,
+        columns: usize,
+        lines: usize,
+        font_size: FontSize,
+        use_busy_flag: bool,
+    ) -> Result<Self, LcdError> {
+        Self::new(i2c, delay, columns, lines, font_size, use_busy_flag, None)
+    }
+}
+
+impl<E, I2C: Write<Error = E> + WriteRead<Error = E>, D: DelayNs, S: Storage> core::fmt::Write
+    for CharLCDRGBI2C<I2C, D, S>
+where
+    LcdError: From<E>,
+{
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.message(s).map_err(|_| core::fmt::Error)
+    }
 }
\ No newline at end of file